@@ -0,0 +1,94 @@
+//! Data ingestion: turns a raw price history into the aligned per-period return slices that
+//! [`crate::Beta`]/[`crate::sharpe`] already expect, so the crate can go from pure math to an
+//! end-to-end workflow (download prices, get betas and Sharpe ratios).
+
+#[cfg(feature = "yahoo_finance")]
+pub mod yahoo;
+
+/// A single (date, adjusted close) observation in a price history, ordered by `date`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricePoint {
+    pub date: String,
+    pub adj_close: f64,
+}
+
+/// Aligns two price histories by date, dropping any date that isn't present in both, so the
+/// resulting series always have equal length -- the invariant [`crate::Beta::new`] asserts on.
+///
+/// Both `series` and `market` are assumed sorted by `date` ascending, matching how a price
+/// history is normally returned.
+pub fn align_by_date(series: &[PricePoint], market: &[PricePoint]) -> (Vec<f64>, Vec<f64>) {
+    let mut series_out = Vec::new();
+    let mut market_out = Vec::new();
+
+    let mut i = 0;
+    let mut j = 0;
+    while i < series.len() && j < market.len() {
+        match series[i].date.cmp(&market[j].date) {
+            std::cmp::Ordering::Equal => {
+                series_out.push(series[i].adj_close);
+                market_out.push(market[j].adj_close);
+                i += 1;
+                j += 1;
+            }
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+        }
+    }
+
+    (series_out, market_out)
+}
+
+/// Converts a price history into per-period simple returns: `(p[i] - p[i-1]) / p[i-1]`.
+///
+/// The result is one element shorter than `prices`, since a return needs a preceding price.
+pub fn simple_returns(prices: &[f64]) -> Vec<f64> {
+    prices.windows(2).map(|w| w[1] / w[0] - 1.0).collect()
+}
+
+/// Converts a price history into per-period log returns: `ln(p[i] / p[i-1])`.
+///
+/// The result is one element shorter than `prices`, since a return needs a preceding price.
+pub fn log_returns(prices: &[f64]) -> Vec<f64> {
+    prices.windows(2).map(|w| (w[1] / w[0]).ln()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn align_by_date_t() {
+        let series = [
+            PricePoint { date: "2026-01-01".into(), adj_close: 100.0 },
+            PricePoint { date: "2026-01-02".into(), adj_close: 102.0 },
+            PricePoint { date: "2026-01-03".into(), adj_close: 101.0 },
+        ];
+        // missing 2026-01-02, so it must be dropped from both sides
+        let market = [
+            PricePoint { date: "2026-01-01".into(), adj_close: 50.0 },
+            PricePoint { date: "2026-01-03".into(), adj_close: 51.0 },
+        ];
+
+        let (aligned_series, aligned_market) = align_by_date(&series, &market);
+
+        assert_eq!(aligned_series, vec![100.0, 101.0]);
+        assert_eq!(aligned_market, vec![50.0, 51.0]);
+        assert_eq!(aligned_series.len(), aligned_market.len());
+    }
+
+    #[test]
+    fn simple_returns_t() {
+        let prices = [100.0, 110.0, 99.0];
+        assert_eq!(
+            simple_returns(&prices),
+            vec![110.0 / 100.0 - 1.0, 99.0 / 110.0 - 1.0]
+        );
+    }
+
+    #[test]
+    fn log_returns_t() {
+        let prices = [100.0, 110.0];
+        assert_eq!(log_returns(&prices), vec![(110.0_f64 / 100.0).ln()]);
+    }
+}
@@ -0,0 +1,97 @@
+//! Fetches historical prices from Yahoo Finance and turns them into the aligned return slices
+//! [`super::align_by_date`]/[`super::simple_returns`] produce. Gated behind the `yahoo_finance`
+//! feature since it pulls in network I/O and the `yahoo_finance_api` crate, which most users of
+//! the pure-math prelude don't need.
+
+use yahoo_finance_api as yahoo;
+
+use super::{align_by_date, log_returns, simple_returns, PricePoint};
+
+/// Errors that can occur while fetching or aligning a ticker's price history.
+#[derive(Debug)]
+pub enum DataError {
+    /// The underlying HTTP/Yahoo Finance request failed.
+    Fetch(yahoo::YahooError),
+    /// Aligning `ticker` against `benchmark` left fewer than two overlapping dates, too few to
+    /// compute a single return from.
+    NoOverlap { ticker: String, benchmark: String },
+}
+
+impl std::fmt::Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataError::Fetch(err) => write!(f, "failed to fetch price history: {err}"),
+            DataError::NoOverlap { ticker, benchmark } => write!(
+                f,
+                "fewer than two overlapping trading dates between {ticker} and {benchmark}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
+
+impl From<yahoo::YahooError> for DataError {
+    fn from(err: yahoo::YahooError) -> Self {
+        DataError::Fetch(err)
+    }
+}
+
+/// Whether to compute simple or log returns from the downloaded adjusted closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnKind {
+    Simple,
+    Log,
+}
+
+/// Downloads the daily adjusted-close history for `ticker` between `start` and `end`
+/// (`time::OffsetDateTime`), e.g. `fetch_price_history("ITC.NS", start, end)`.
+pub async fn fetch_price_history(
+    ticker: &str,
+    start: time::OffsetDateTime,
+    end: time::OffsetDateTime,
+) -> Result<Vec<PricePoint>, DataError> {
+    let provider = yahoo::YahooConnector::new()?;
+    let response = provider.get_quote_history(ticker, start, end).await?;
+    let quotes = response.quotes()?;
+
+    Ok(quotes
+        .into_iter()
+        .map(|quote| PricePoint {
+            date: time::OffsetDateTime::from_unix_timestamp(quote.timestamp)
+                .map(|dt| dt.date().to_string())
+                .unwrap_or_default(),
+            adj_close: quote.adjclose,
+        })
+        .collect())
+}
+
+/// Downloads `ticker` and `market` (e.g. `"ITC.NS"` and `"^NSEI"` for NIFTY 50), aligns them by
+/// date so [`crate::Beta::new`]'s `series.len() == market.len()` invariant always holds, and
+/// converts the aligned adjusted closes into per-period returns ready for
+/// [`crate::Beta::new`]/[`crate::sharpe`].
+pub async fn fetch_aligned_returns(
+    ticker: &str,
+    market: &str,
+    start: time::OffsetDateTime,
+    end: time::OffsetDateTime,
+    kind: ReturnKind,
+) -> Result<(Vec<f64>, Vec<f64>), DataError> {
+    let ticker_history = fetch_price_history(ticker, start, end).await?;
+    let market_history = fetch_price_history(market, start, end).await?;
+
+    let (aligned_ticker, aligned_market) = align_by_date(&ticker_history, &market_history);
+    if aligned_ticker.len() < 2 {
+        return Err(DataError::NoOverlap {
+            ticker: ticker.to_string(),
+            benchmark: market.to_string(),
+        });
+    }
+
+    let to_returns = match kind {
+        ReturnKind::Simple => simple_returns,
+        ReturnKind::Log => log_returns,
+    };
+
+    Ok((to_returns(&aligned_ticker), to_returns(&aligned_market)))
+}
@@ -1,3 +1,5 @@
+pub mod data;
+pub mod order_statistics;
 pub mod ratios;
 
 pub mod prelude;
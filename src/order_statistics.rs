@@ -0,0 +1,186 @@
+//! Order statistics: functions whose result depends on the *rank* of values rather than their
+//! sum, e.g. [`median`]/[`quantile`] for robust central tendency and [`winsorize`] for clamping
+//! outlier return spikes before feeding a series into [`crate::variance`]/[`crate::Beta`].
+
+/// Sorts a copy of `series`, ordering `NaN` as greater than every other value (including
+/// `+inf`) so a series containing bad data points still produces a well-defined ordering instead
+/// of panicking or silently misordering.
+fn sorted_copy(series: &[f64]) -> Vec<f64> {
+    let mut sorted = series.to_vec();
+    sorted.sort_by(|a, b| match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.total_cmp(b),
+        (a_nan, b_nan) => a_nan.cmp(&b_nan),
+    });
+    sorted
+}
+
+/// # Quantile
+///
+/// The value below which a fraction `p` of `series` falls, using linear interpolation between
+/// the two closest ranks (the same convention as Excel's `PERCENTILE.INC` / NumPy's default).
+///
+/// 1. sort a copy of `series`
+/// 2. compute the fractional rank `h = (n - 1) * p`
+/// 3. interpolate `sorted[floor(h)] + (h - floor(h)) * (sorted[ceil(h)] - sorted[floor(h)])`
+///
+/// `p` is clamped to `[0.0, 1.0]`. Returns `0.0` for an empty `series`.
+pub fn quantile(series: &[f64], p: f64) -> f64 {
+    if series.is_empty() {
+        return 0.0;
+    }
+
+    let sorted = sorted_copy(series);
+    let n = sorted.len();
+    let p = p.clamp(0.0, 1.0);
+    let h = (n - 1) as f64 * p;
+    let lo = h.floor() as usize;
+    let hi = h.ceil() as usize;
+
+    sorted[lo] + (h - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
+/// # Quantile Macro
+///
+/// Calculates the `p`-quantile (`p` in `[0, 1]`) of a given slice of `f64`.
+#[macro_export]
+macro_rules! quantile {
+    ($series: expr, $p: expr) => {
+        $crate::order_statistics::quantile($series, $p)
+    };
+}
+
+/// # Percentile
+///
+/// Same as [`quantile`] but takes `p` on a `0..=100` scale, e.g. `percentile(series, 90.0)` is
+/// the 90th percentile.
+pub fn percentile(series: &[f64], p: f64) -> f64 {
+    quantile(series, p / 100.0)
+}
+
+/// # Percentile Macro
+///
+/// Calculates the `p`-th percentile (`p` in `[0, 100]`) of a given slice of `f64`.
+#[macro_export]
+macro_rules! percentile {
+    ($series: expr, $p: expr) => {
+        $crate::order_statistics::percentile($series, $p)
+    };
+}
+
+/// # Median
+///
+/// The middle value of `series`: the 50th percentile / 0.5-quantile. Unlike [`crate::mean`], the
+/// median is robust to a handful of extreme outliers.
+pub fn median(series: &[f64]) -> f64 {
+    quantile(series, 0.5)
+}
+
+/// # Median Macro
+///
+/// Calculates the median of a given slice of `f64`.
+#[macro_export]
+macro_rules! median {
+    ($series: expr) => {
+        $crate::order_statistics::median($series)
+    };
+}
+
+/// # Winsorize
+///
+/// Clamps every value below the `lower_p` quantile to that quantile, and every value above the
+/// `upper_p` quantile to that quantile, returning a new `Vec<f64>`. Useful for taming outlier
+/// return spikes before computing [`crate::variance`]/[`crate::Beta`], which are both sensitive
+/// to extreme values.
+///
+/// Both `lower_p` and `upper_p` are clamped to `[0.0, 1.0]` by [`quantile`]; a `series` of all
+/// equal values winsorizes to itself, since every quantile equals that value.
+///
+/// Deliberately uses manual comparisons rather than `f64::clamp`: `quantile` can return `NaN`
+/// (e.g. a `series` containing a stray `NaN`, which it sorts as the largest value) and
+/// `f64::clamp` panics on a `NaN` bound or on `lower > upper` (a swapped-argument caller
+/// mistake), even in release builds. `x < lower`/`x > upper` are simply `false` for a `NaN`
+/// operand, so both cases degrade gracefully instead of panicking.
+pub fn winsorize(series: &[f64], lower_p: f64, upper_p: f64) -> Vec<f64> {
+    let lower = quantile(series, lower_p);
+    let upper = quantile(series, upper_p);
+
+    series
+        .iter()
+        .map(|&x| {
+            if x < lower {
+                lower
+            } else if x > upper {
+                upper
+            } else {
+                x
+            }
+        })
+        .collect()
+}
+
+/// # Winsorize Macro
+///
+/// Winsorizes a given slice of `f64` at the `lower_p`/`upper_p` quantiles.
+#[macro_export]
+macro_rules! winsorize {
+    ($series: expr, $lower_p: expr, $upper_p: expr) => {
+        $crate::order_statistics::winsorize($series, $lower_p, $upper_p)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    const SERIES: [f64; 6] = [4.0, 34.0, 18.0, 12.0, 2.0, 26.0];
+
+    #[test]
+    fn median_t() {
+        assert_eq!(median!(&SERIES), 15.0);
+        assert_eq!(median!(&[1.0, 2.0, 3.0]), 2.0);
+    }
+
+    #[test]
+    fn quantile_t() {
+        assert_eq!(quantile!(&SERIES, 0.25), 6.0);
+        assert_eq!(quantile!(&SERIES, 0.75), 24.0);
+        assert_eq!(percentile!(&SERIES, 75.0), 24.0);
+
+        // edge cases: empty slice, out-of-range p, all-equal input
+        assert_eq!(quantile!(&[], 0.5), 0.0);
+        assert_eq!(quantile!(&SERIES, -1.0), quantile!(&SERIES, 0.0));
+        assert_eq!(quantile!(&SERIES, 2.0), quantile!(&SERIES, 1.0));
+        assert_eq!(quantile!(&[5.0, 5.0, 5.0], 0.3), 5.0);
+    }
+
+    #[test]
+    fn winsorize_t() {
+        let result = winsorize!(&SERIES, 0.1, 0.9);
+        assert_eq!(result, vec![4.0, 30.0, 18.0, 12.0, 3.0, 26.0]);
+
+        // all-equal input winsorizes to itself
+        let flat = [5.0, 5.0, 5.0];
+        assert_eq!(winsorize!(&flat, 0.1, 0.9), vec![5.0, 5.0, 5.0]);
+    }
+
+    #[test]
+    fn winsorize_nan_series_t() {
+        // `quantile` sorts NaN as the largest value, so a high upper_p can make the upper
+        // bound itself NaN -- must not panic.
+        let series = [1.0, 2.0, 3.0, f64::NAN];
+
+        let result = winsorize!(&series, 0.1, 0.9);
+
+        assert_eq!(result.len(), series.len());
+        assert_eq!(result[0], 1.3);
+        assert_eq!(result[1], 2.0);
+        assert_eq!(result[2], 3.0);
+        assert!(result[3].is_nan());
+    }
+
+    #[test]
+    fn winsorize_swapped_bounds_t() {
+        // lower_p > upper_p is an easy caller mistake; it must not panic either.
+        let result = winsorize!(&SERIES, 0.9, 0.1);
+
+        assert_eq!(result, vec![30.0, 3.0, 30.0, 30.0, 30.0, 30.0]);
+    }
+}
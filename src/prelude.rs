@@ -1,14 +1,33 @@
+/// Kahan (compensated) summation: tracks a running compensation term `c` so that the rounding
+/// error from each addition is fed back into the next one, instead of accumulating unchecked the
+/// way a naive `iter().sum()` does over a long or large-magnitude series.
+///
+/// ref: https://en.wikipedia.org/wiki/Kahan_summation_algorithm
+fn kahan_sum<I: IntoIterator<Item = f64>>(values: I) -> f64 {
+    let mut sum = 0.0;
+    let mut c = 0.0;
+    for x in values {
+        let y = x - c;
+        let t = sum + y;
+        c = (t - sum) - y;
+        sum = t;
+    }
+    sum
+}
+
 /// # Arithmetic mean
 ///
 /// formula: mean = total / n
 pub fn mean(series: &[f64]) -> f64 {
     let n = series.len() as f64;
-    series.iter().sum::<f64>() / n
+    kahan_sum(series.iter().copied()) / n
 }
 
 /// # Arithmetic Mean Macro
 ///
-/// Calculates the average of a given slice of `f64`.
+/// Calculates the average of a given slice of `f64`. `mean!(@acc acc)` folds over a streaming
+/// [`Accumulator`](crate::prelude::Accumulator) instead, for when the whole series can't be
+/// held in memory at once.
 ///
 /// ## Usage
 /// ```rust
@@ -24,6 +43,10 @@ macro_rules! mean {
     ($series: expr) => {
         $crate::prelude::mean($series)
     };
+    // folds over a streaming `Accumulator` instead of a `&[f64]`
+    (@acc $acc: expr) => {
+        $acc.mean()
+    };
 }
 
 /// # Variance
@@ -40,23 +63,52 @@ macro_rules! mean {
 ///
 /// mean: Option<f64> => if None: function will calculate from series
 ///
+/// This is the *population* variance (divides by `n`). See [`sample_variance`] for the
+/// `n - 1` (Bessel-corrected) estimator used when `series` is a sample drawn from a larger
+/// population, e.g. a return series used to estimate risk.
+///
 /// ref: https://en.wikipedia.org/wiki/Variance
 pub fn variance(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    population_variance(series, pre_computed_mean)
+}
+
+/// # Variance with delta degrees of freedom
+///
+/// Generalizes [`variance`] to divide the sum of squared deviations by `n - ddof` instead of
+/// always dividing by `n`. `ddof = 0.0` is the population variance, `ddof = 1.0` is the sample
+/// (Bessel-corrected) variance.
+///
+/// Returns `0.0` when `n <= ddof` instead of dividing by zero or a negative count.
+pub fn variance_ddof(series: &[f64], pre_computed_mean: Option<f64>, ddof: f64) -> f64 {
     let count = series.len() as f64;
-    // 01
+    if count <= ddof {
+        return 0.0;
+    }
+
     let mean = match pre_computed_mean {
         Some(m) => m,
         None => mean!(series),
     };
-    series
-        .iter()
-        .map(|x| {
-            // 02
-            let diff = x - mean;
-            diff * diff
-        })
-        .sum::<f64>()
-        / count // 03
+    kahan_sum(series.iter().map(|x| {
+        let diff = x - mean;
+        diff * diff
+    })) / (count - ddof)
+}
+
+/// # Population Variance
+///
+/// `variance_ddof` with `ddof = 0.0`, i.e. divides by `n`. This is what [`variance`] computes.
+pub fn population_variance(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    variance_ddof(series, pre_computed_mean, 0.0)
+}
+
+/// # Sample Variance
+///
+/// `variance_ddof` with `ddof = 1.0` (Bessel's correction), i.e. divides by `n - 1`. Use this
+/// when `series` is a sample used to estimate the variance of a larger population, e.g. the
+/// variance feeding into the Sharpe ratio.
+pub fn sample_variance(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    variance_ddof(series, pre_computed_mean, 1.0)
 }
 
 /// # Variance Macro
@@ -67,6 +119,7 @@ pub fn variance(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
 ///
 /// 1. `variance!(x)` - Calculates the mean automatically before computing variance.
 /// 2. `variance!(x, mean)` - Uses pre computed mean
+/// 3. `variance!(@acc acc, ddof)` - Folds over a streaming [`Accumulator`](crate::prelude::Accumulator)
 ///
 /// where,
 ///     x = `&[f64]`
@@ -94,6 +147,43 @@ macro_rules! variance {
     ($series: expr, $pre_computed_mean: expr) => {
         $crate::prelude::variance($series, Some($pre_computed_mean))
     };
+    (@acc $acc: expr, $ddof: expr) => {
+        $acc.variance($ddof)
+    };
+}
+
+/// # Population Variance Macro
+///
+/// Calculates the population variance (divides by `n`) of a given slice of `f64`.
+///
+/// ## Usage
+/// 1. `population_variance!(x)` - Calculates the mean automatically before computing variance.
+/// 2. `population_variance!(x, mean)` - Uses pre computed mean
+#[macro_export]
+macro_rules! population_variance {
+    ($series: expr) => {
+        $crate::prelude::population_variance($series, None)
+    };
+    ($series: expr, $pre_computed_mean: expr) => {
+        $crate::prelude::population_variance($series, Some($pre_computed_mean))
+    };
+}
+
+/// # Sample Variance Macro
+///
+/// Calculates the sample variance (divides by `n - 1`) of a given slice of `f64`.
+///
+/// ## Usage
+/// 1. `sample_variance!(x)` - Calculates the mean automatically before computing variance.
+/// 2. `sample_variance!(x, mean)` - Uses pre computed mean
+#[macro_export]
+macro_rules! sample_variance {
+    ($series: expr) => {
+        $crate::prelude::sample_variance($series, None)
+    };
+    ($series: expr, $pre_computed_mean: expr) => {
+        $crate::prelude::sample_variance($series, Some($pre_computed_mean))
+    };
 }
 
 /// # Covariance
@@ -106,11 +196,11 @@ pub fn covariance(x: &[f64], y: &[f64], pre_computed_mean: Option<(f64, f64)>) -
     };
 
     assert_eq!(x.len(), y.len());
-    let total = x
-        .iter()
-        .zip(y)
-        .map(|(xi, yi)| (xi - mean_x) * (yi - mean_y))
-        .sum::<f64>();
+    let total = kahan_sum(
+        x.iter()
+            .zip(y)
+            .map(|(xi, yi)| (xi - mean_x) * (yi - mean_y)),
+    );
     total / (x.len() as f64)
 }
 
@@ -171,6 +261,21 @@ macro_rules! covariance {
 pub fn standard_deviation(variance: f64) -> f64 {
     variance.sqrt()
 }
+
+/// # Population Standard Deviation
+///
+/// `sqrt` of [`population_variance`], i.e. divides by `n`.
+pub fn population_sd(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    standard_deviation(population_variance(series, pre_computed_mean))
+}
+
+/// # Sample Standard Deviation
+///
+/// `sqrt` of [`sample_variance`], i.e. divides by `n - 1` (Bessel's correction).
+pub fn sample_sd(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    standard_deviation(sample_variance(series, pre_computed_mean))
+}
+
 /// # Standard Deviation Macro
 ///
 /// Calculates the standard deviation of a given slice of `f64`.
@@ -203,9 +308,336 @@ macro_rules! sd {
     };
 }
 
+/// # Population Standard Deviation Macro
+///
+/// ## Usage
+/// 1. `population_sd!(x)` - Calculates the mean automatically before computing sd.
+/// 2. `population_sd!(x, mean)` - Uses pre computed mean
+#[macro_export]
+macro_rules! population_sd {
+    ($series: expr) => {
+        $crate::prelude::population_sd($series, None)
+    };
+    ($series: expr, $pre_computed_mean: expr) => {
+        $crate::prelude::population_sd($series, Some($pre_computed_mean))
+    };
+}
+
+/// # Sample Standard Deviation Macro
+///
+/// ## Usage
+/// 1. `sample_sd!(x)` - Calculates the mean automatically before computing sd.
+/// 2. `sample_sd!(x, mean)` - Uses pre computed mean
+#[macro_export]
+macro_rules! sample_sd {
+    ($series: expr) => {
+        $crate::prelude::sample_sd($series, None)
+    };
+    ($series: expr, $pre_computed_mean: expr) => {
+        $crate::prelude::sample_sd($series, Some($pre_computed_mean))
+    };
+}
+
+/// # Skewness
+///
+/// Sample skewness: a measure of the asymmetry of a distribution around its mean. Positive
+/// skew means a longer right tail (occasional large gains), negative skew a longer left tail
+/// (occasional large losses) -- relevant for return series where [`variance`] alone hides which
+/// side the tail risk is on.
+///
+/// Computed from the central moments `m2 = mean((x - mean)^2)`, `m3 = mean((x - mean)^3)` as
+/// `g1 = m3 / m2^1.5`.
+///
+/// Returns `0.0` when `m2` is below `f64::EPSILON` or `series` has fewer than 2 points, since
+/// skewness is undefined for a degenerate (constant or empty) series.
+pub fn skewness(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    if series.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = match pre_computed_mean {
+        Some(m) => m,
+        None => mean!(series),
+    };
+    let n = series.len() as f64;
+    let m2 = series.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    if m2 < f64::EPSILON {
+        return 0.0;
+    }
+    let m3 = series.iter().map(|x| (x - mean).powi(3)).sum::<f64>() / n;
+
+    m3 / m2.powf(1.5)
+}
+
+/// # Skewness Macro
+///
+/// ## Usage
+/// 1. `skewness!(x)` - Calculates the mean automatically before computing skewness.
+/// 2. `skewness!(x, mean)` - Uses pre computed mean
+#[macro_export]
+macro_rules! skewness {
+    ($series: expr) => {
+        $crate::prelude::skewness($series, None)
+    };
+    ($series: expr, $pre_computed_mean: expr) => {
+        $crate::prelude::skewness($series, Some($pre_computed_mean))
+    };
+}
+
+/// # Kurtosis
+///
+/// Excess kurtosis: how fat the tails of a distribution are relative to a normal distribution.
+/// `0.0` means normal-like tails, positive values mean fatter tails (more extreme returns than
+/// a normal distribution would predict) -- the flip side of [`skewness`] for tail risk.
+///
+/// Computed from the central moments `m2 = mean((x - mean)^2)`, `m4 = mean((x - mean)^4)` as
+/// `g2 = m4 / (m2 * m2) - 3.0` (the `-3.0` makes a normal distribution's kurtosis read as `0.0`).
+///
+/// Returns `0.0` when `m2` is below `f64::EPSILON` or `series` has fewer than 2 points.
+pub fn kurtosis(series: &[f64], pre_computed_mean: Option<f64>) -> f64 {
+    if series.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = match pre_computed_mean {
+        Some(m) => m,
+        None => mean!(series),
+    };
+    let n = series.len() as f64;
+    let m2 = series.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    if m2 < f64::EPSILON {
+        return 0.0;
+    }
+    let m4 = series.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / n;
+
+    m4 / (m2 * m2) - 3.0
+}
+
+/// # Kurtosis Macro
+///
+/// ## Usage
+/// 1. `kurtosis!(x)` - Calculates the mean automatically before computing kurtosis.
+/// 2. `kurtosis!(x, mean)` - Uses pre computed mean
+#[macro_export]
+macro_rules! kurtosis {
+    ($series: expr) => {
+        $crate::prelude::kurtosis($series, None)
+    };
+    ($series: expr, $pre_computed_mean: expr) => {
+        $crate::prelude::kurtosis($series, Some($pre_computed_mean))
+    };
+}
+
+/// # Accumulator
+///
+/// A single-pass, streaming mean/variance estimator. Unlike [`mean`]/[`variance`], which need
+/// the whole slice in memory, an `Accumulator` is updated one observation at a time via
+/// [`Accumulator::add`] and can later be folded into `mean!`/`variance!` via the `@acc` form of
+/// those macros, making it a drop-in replacement when the full return series doesn't fit in
+/// memory (e.g. a large tick stream).
+///
+/// Uses Welford's online algorithm: for each new `x` with running count `k`,
+/// `delta = x - mean`, `mean += delta / k`, `m2 += delta * (x - mean)`.
+///
+/// ref: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// Also carries the `M3`/`M4` terms needed for [`Accumulator::skewness`] and
+/// [`Accumulator::kurtosis`] alongside `M2`, updated in the same single pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Accumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl Accumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds a new observation into the running mean/variance/skewness/kurtosis in a single
+    /// pass.
+    pub fn add(&mut self, x: f64) {
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    /// Combines two accumulators, e.g. one per thread, into the accumulator they would have
+    /// produced had every observation been folded into a single one.
+    ///
+    /// Uses Chan et al.'s parallel combination formula: with counts `nA,nB`, means `mA,mB`,
+    /// second moments `M2A,M2B`, `delta = mB - mA`, `n = nA + nB`,
+    /// combined mean `= mA + delta * nB / n`,
+    /// combined `M2 = M2A + M2B + delta * delta * nA * nB / n`.
+    ///
+    /// `M3`/`M4` are combined with Pébay's extension of the same formula.
+    ///
+    /// ref: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Parallel_algorithm
+    pub fn merge(&self, other: &Self) -> Self {
+        if self.count == 0 {
+            return *other;
+        }
+        if other.count == 0 {
+            return *self;
+        }
+
+        let n_a = self.count as f64;
+        let n_b = other.count as f64;
+        let n = n_a + n_b;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+
+        let m2 = self.m2 + other.m2 + delta2 * n_a * n_b / n;
+        let m3 = self.m3
+            + other.m3
+            + delta * delta2 * n_a * n_b * (n_a - n_b) / (n * n)
+            + 3.0 * delta * (n_a * other.m2 - n_b * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta2 * delta2 * n_a * n_b * (n_a * n_a - n_a * n_b + n_b * n_b) / (n * n * n)
+            + 6.0 * delta2 * (n_a * n_a * other.m2 + n_b * n_b * self.m2) / (n * n)
+            + 4.0 * delta * (n_a * other.m3 - n_b * self.m3) / n;
+
+        Self {
+            count: self.count + other.count,
+            mean: self.mean + delta * n_b / n,
+            m2,
+            m3,
+            m4,
+        }
+    }
+
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Variance with delta degrees of freedom, mirroring [`variance_ddof`]: `ddof = 0.0` for the
+    /// population variance, `ddof = 1.0` for the sample variance. Returns `0.0` when
+    /// `count <= ddof` instead of dividing by zero or a negative count.
+    pub fn variance(&self, ddof: f64) -> f64 {
+        let n = self.count as f64;
+        if n <= ddof {
+            return 0.0;
+        }
+        self.m2 / (n - ddof)
+    }
+
+    /// Sample skewness over all observations folded into this accumulator so far. See
+    /// [`skewness`] for the formula; returns `0.0` under the same degenerate conditions.
+    pub fn skewness(&self) -> f64 {
+        let n = self.count as f64;
+        if self.count < 2 {
+            return 0.0;
+        }
+        let m2 = self.m2 / n;
+        if m2 < f64::EPSILON {
+            return 0.0;
+        }
+        let m3 = self.m3 / n;
+        m3 / m2.powf(1.5)
+    }
+
+    /// Excess kurtosis over all observations folded into this accumulator so far. See
+    /// [`kurtosis`] for the formula; returns `0.0` under the same degenerate conditions.
+    pub fn kurtosis(&self) -> f64 {
+        let n = self.count as f64;
+        if self.count < 2 {
+            return 0.0;
+        }
+        let m2 = self.m2 / n;
+        if m2 < f64::EPSILON {
+            return 0.0;
+        }
+        let m4 = self.m4 / n;
+        m4 / (m2 * m2) - 3.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::covariance;
+    use crate::prelude::Accumulator;
+
+    #[test]
+    fn accumulator_t() {
+        let series = [4.0, 34.0, 18.0, 12.0, 2.0, 26.0];
+
+        let mut acc = Accumulator::new();
+        for x in series {
+            acc.add(x);
+        }
+
+        assert_eq!(acc.count(), series.len() as u64);
+        assert_eq!(mean!(@acc acc), mean!(&series));
+        assert_eq!(variance!(@acc acc, 0.0), variance!(&series));
+        assert_eq!(variance!(@acc acc, 1.0), sample_variance!(&series));
+    }
+
+    #[test]
+    fn skewness_kurtosis_t() {
+        let series = [4.0, 34.0, 18.0, 12.0, 2.0, 26.0];
+
+        assert_eq!(skewness!(&series), 0.2570893024495464);
+        assert_eq!(kurtosis!(&series), -1.297584339858392);
+
+        // degenerate series: all-equal and too-short must not blow up
+        assert_eq!(skewness!(&[5.0, 5.0, 5.0]), 0.0);
+        assert_eq!(kurtosis!(&[5.0, 5.0, 5.0]), 0.0);
+        assert_eq!(skewness!(&[1.0]), 0.0);
+        assert_eq!(kurtosis!(&[]), 0.0);
+
+        let mut acc = Accumulator::new();
+        for x in series {
+            acc.add(x);
+        }
+        assert_eq!(acc.skewness(), skewness!(&series));
+        assert_eq!(acc.kurtosis(), kurtosis!(&series));
+    }
+
+    #[test]
+    fn accumulator_merge_t() {
+        let series = [4.0, 34.0, 18.0, 12.0, 2.0, 26.0];
+
+        let mut whole = Accumulator::new();
+        for x in series {
+            whole.add(x);
+        }
+
+        let mut a = Accumulator::new();
+        let mut b = Accumulator::new();
+        for x in &series[..3] {
+            a.add(*x);
+        }
+        for x in &series[3..] {
+            b.add(*x);
+        }
+        let merged = a.merge(&b);
+
+        assert_eq!(merged.count(), whole.count());
+        assert_eq!(merged.mean(), whole.mean());
+        // the merge combines two already-rounded M2 terms, so it can differ from the
+        // single-pass accumulator in the last bit or two
+        assert_eq!(merged.variance(0.0), 130.66666666666666);
+        assert!((merged.variance(0.0) - whole.variance(0.0)).abs() < 1e-9);
+    }
 
     #[test]
     fn test_prelude() {
@@ -219,6 +651,23 @@ mod test {
         assert_eq!(sd, 11.430952132988164); // 11.43
     }
 
+    #[test]
+    fn sample_vs_population_variance_t() {
+        let series = [4, 34, 18, 12, 2, 26].map(|x| x as f64);
+
+        let pop_var = population_variance!(&series);
+        let samp_var = sample_variance!(&series);
+
+        assert_eq!(pop_var, variance!(&series));
+        assert_eq!(samp_var, 156.8);
+        assert_eq!(sample_sd!(&series), 12.521980673998822);
+
+        // n <= ddof must not divide by zero or a negative count
+        let one = [42.0];
+        assert_eq!(sample_variance!(&one), 0.0);
+        assert_eq!(super::variance_ddof(&[], None, 1.0), 0.0);
+    }
+
     #[test]
     fn covariance_t() {
         let hours = [3, 5, 2, 7, 4].map(|x| x as f64);
@@ -0,0 +1 @@
+pub mod risk_metrics;
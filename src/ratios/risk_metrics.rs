@@ -1,4 +1,4 @@
-use crate::{covariance, mean, sd, variance};
+use crate::{covariance, mean, sample_sd, variance};
 
 pub struct Beta(f64);
 
@@ -74,6 +74,8 @@ impl From<Beta> for f64 {
     }
 }
 
+/// Sharpe ratio: excess return over the risk-free rate, divided by the *sample* standard
+/// deviation of `series` (Bessel-corrected, i.e. `n - 1`).
 pub fn sharpe(series: &[f64], rf: f64) -> f64 {
     internal_sharpe(Some(series), rf, None, None)
 }
@@ -83,7 +85,9 @@ fn internal_sharpe(series: Option<&[f64]>, rf: f64, rp: Option<f64>, sd: Option<
     let std_div: f64;
     if let Some(series) = series {
         portfolio_ret = mean!(series);
-        std_div = sd!(variance!(series, portfolio_ret));
+        // Sharpe is conventionally computed against the *sample* standard deviation
+        // (Bessel's correction), since a return series is a sample, not the whole population.
+        std_div = sample_sd!(series, portfolio_ret);
     } else {
         assert!(rp.is_some(), "");
         assert!(sd.is_some(), "");
@@ -110,9 +114,153 @@ macro_rules! sharpe {
     };
 }
 
+/// Sortino ratio: like [`sharpe`], but penalizes only *downside* volatility instead of total
+/// volatility. Divides the excess return over `rf` by the downside deviation -- the RMS of the
+/// deviations below `target` (the minimum acceptable return), with deviations above `target`
+/// counted as zero: `sqrt(mean(min(x - target, 0)^2))`.
+///
+/// Returns `0.0` for an empty `series`, or when the downside deviation is below `f64::EPSILON`,
+/// i.e. `series` never fell below `target`.
+pub fn sortino(series: &[f64], rf: f64, target: f64) -> f64 {
+    if series.is_empty() {
+        return 0.0;
+    }
+
+    let portfolio_ret = mean!(series);
+    let downside_variance = series
+        .iter()
+        .map(|x| (x - target).min(0.0).powi(2))
+        .sum::<f64>()
+        / series.len() as f64;
+    let downside_deviation = downside_variance.sqrt();
+
+    if downside_deviation < f64::EPSILON {
+        return 0.0;
+    }
+
+    (portfolio_ret - rf) / downside_deviation
+}
+
+/// Treynor ratio: like [`sharpe`], but divides the excess return over `rf` by the portfolio's
+/// market [`Beta`] instead of its standard deviation -- a risk-adjusted return measure for a
+/// diversified portfolio, where systematic (market) risk is what remains.
+///
+/// Returns `0.0` when `|beta|` is below `f64::EPSILON`, i.e. the portfolio is uncorrelated with
+/// `market`.
+pub fn treynor(series: &[f64], market: &[f64], rf: f64) -> f64 {
+    let portfolio_ret = mean!(series);
+    let beta = Beta::new(series, market).value();
+
+    if beta.abs() < f64::EPSILON {
+        return 0.0;
+    }
+
+    (portfolio_ret - rf) / beta
+}
+
+/// A peak-to-trough decline found by [`max_drawdown`]/[`max_drawdown_from_returns`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drawdown {
+    /// The largest decline from a running peak, as a fraction of that peak (e.g. `0.25` for a
+    /// 25% drop).
+    pub max_drawdown: f64,
+    /// Index into the series of the peak the drawdown fell from.
+    pub peak_index: usize,
+    /// Index into the series of the trough the drawdown bottomed out at.
+    pub trough_index: usize,
+}
+
+/// # Maximum Drawdown
+///
+/// Walks `price_series` (or any cumulative wealth curve) tracking the running peak; at each
+/// point computes `(peak - value) / peak` and keeps the largest such decline, along with the
+/// peak and trough indices it occurred between.
+///
+/// Use [`max_drawdown_from_returns`] instead when you only have the per-period simple returns
+/// that [`Beta`]/[`sharpe`] already work with, rather than a price series.
+///
+/// Returns a zeroed [`Drawdown`] for an empty `price_series`.
+pub fn max_drawdown(price_series: &[f64]) -> Drawdown {
+    let Some(&first) = price_series.first() else {
+        return Drawdown {
+            max_drawdown: 0.0,
+            peak_index: 0,
+            trough_index: 0,
+        };
+    };
+
+    let mut peak = first;
+    let mut peak_index = 0;
+
+    let mut worst = Drawdown {
+        max_drawdown: 0.0,
+        peak_index: 0,
+        trough_index: 0,
+    };
+
+    for (i, &value) in price_series.iter().enumerate() {
+        if value > peak {
+            peak = value;
+            peak_index = i;
+        }
+
+        let drawdown = (peak - value) / peak;
+        if drawdown > worst.max_drawdown {
+            worst = Drawdown {
+                max_drawdown: drawdown,
+                peak_index,
+                trough_index: i,
+            };
+        }
+    }
+
+    worst
+}
+
+/// Reconstructs a cumulative wealth curve from per-period simple returns via the running product
+/// `(1 + r_i)`, starting from a wealth of `1.0`.
+pub fn cumulative_wealth(returns: &[f64]) -> Vec<f64> {
+    let mut wealth = 1.0;
+    returns
+        .iter()
+        .map(|r| {
+            wealth *= 1.0 + r;
+            wealth
+        })
+        .collect()
+}
+
+/// [`max_drawdown`] over the cumulative wealth curve implied by per-period simple `returns`, so
+/// the same return slices used by [`Beta`]/[`sharpe`] can be analyzed for worst peak-to-trough
+/// loss without a separate price series.
+pub fn max_drawdown_from_returns(returns: &[f64]) -> Drawdown {
+    max_drawdown(&cumulative_wealth(returns))
+}
+
+/// # Calmar Ratio
+///
+/// Annualized return divided by [`max_drawdown_from_returns`] -- a risk-adjusted return measure
+/// that penalizes large peak-to-trough losses rather than volatility. `periods_per_year`
+/// annualizes the arithmetic mean return (e.g. `12` for monthly `series`, `252` for daily).
+///
+/// Returns `0.0` when the max drawdown is below `f64::EPSILON`, i.e. `series` never declined
+/// from its running peak.
+pub fn calmar(series: &[f64], periods_per_year: f64) -> f64 {
+    let annualized_return = mean!(series) * periods_per_year;
+    let drawdown = max_drawdown_from_returns(series).max_drawdown;
+
+    if drawdown < f64::EPSILON {
+        return 0.0;
+    }
+
+    annualized_return / drawdown
+}
+
 #[cfg(test)]
 mod test {
-    use crate::ratios::risk_metrics::sharpe;
+    use crate::ratios::risk_metrics::{
+        calmar, max_drawdown, max_drawdown_from_returns, sharpe, sortino, treynor,
+    };
 
     use super::Beta;
     
@@ -165,7 +313,7 @@ mod test {
     fn beta_t() {
 
         let beta: f64 = Beta::new(&ITC, &NIFTY_50).into();
-        assert_eq!(beta, -0.13098715705340794);
+        assert_eq!(beta, -0.13098715705340777);
     }
 
     #[test]
@@ -179,7 +327,54 @@ mod test {
         let s3 = sharpe(&ITC, rf);
         let s2 = sharpe!(protfolio_return, rf, annaulized_sd);
         assert_eq!(s2, 1.25);
-        assert_eq!(s1, -3.024907069875915);
-        assert_eq!(s3, -3.024907069875915);
+        assert_eq!(s1, -2.9442283419825706);
+        assert_eq!(s3, -2.9442283419825706);
+    }
+
+    #[test]
+    fn sortino_t() {
+        let rf = 0.03;
+
+        assert_eq!(sortino(&ITC, rf, 0.0), -2.821042429999175);
+
+        // a series that never falls below target has a zero downside deviation
+        let always_up = [0.01, 0.02, 0.03];
+        assert_eq!(sortino(&always_up, rf, 0.0), 0.0);
+
+        // an empty series must not divide 0.0 / 0.0 into NaN
+        assert_eq!(sortino(&[], rf, 0.0), 0.0);
+    }
+
+    #[test]
+    fn treynor_t() {
+        let rf = 0.03;
+
+        assert_eq!(treynor(&ITC, &NIFTY_50, rf), 0.27722666158911435);
+    }
+
+    #[test]
+    fn max_drawdown_t() {
+        let prices = [100.0, 110.0, 90.0, 95.0, 80.0, 120.0];
+
+        let dd = max_drawdown(&prices);
+        assert_eq!(dd.peak_index, 1);
+        assert_eq!(dd.trough_index, 4);
+        assert_eq!(dd.max_drawdown, (110.0 - 80.0) / 110.0);
+
+        let dd_from_returns = max_drawdown_from_returns(&ITC);
+        assert_eq!(dd_from_returns.max_drawdown, 0.08984422667812733);
+        assert_eq!(dd_from_returns.peak_index, 0);
+        assert_eq!(dd_from_returns.trough_index, 17);
+    }
+
+    #[test]
+    fn calmar_t() {
+        let monthly_periods_per_year = 12.0;
+
+        assert_eq!(calmar(&ITC, monthly_periods_per_year), -0.843210409089383);
+
+        // a series that never drops below its running peak has a zero drawdown
+        let always_up = [0.01, 0.02, 0.03];
+        assert_eq!(calmar(&always_up, monthly_periods_per_year), 0.0);
     }
 }